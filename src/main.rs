@@ -1,14 +1,131 @@
 use clap::Parser;
-use rand::Rng;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use signal_hook::iterator::Signals;
+use siphasher::sip::SipHasher13;
 use std::fs::File;
-use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::hash::Hasher;
+use std::io::{self, Read};
 use std::num::NonZeroU64;
+use std::os::unix::fs::{FileExt, OpenOptionsExt};
+use std::os::unix::io::AsRawFd;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::thread::{self, JoinHandle};
 use std::time::{Duration, Instant};
 
+/// A block of memory whose base pointer is aligned to a device's logical sector size,
+/// required for `O_DIRECT` I/O. Falls back to a plain heap allocation when `align <= 1`.
+struct AlignedBuffer {
+    ptr: *mut u8,
+    len: usize,
+    layout: std::alloc::Layout,
+}
+
+impl AlignedBuffer {
+    fn new(len: usize, align: usize) -> Self {
+        let align = align.max(1).next_power_of_two();
+        let layout = std::alloc::Layout::from_size_align(len.max(align), align)
+            .expect("invalid O_DIRECT buffer layout");
+
+        let ptr = unsafe { std::alloc::alloc_zeroed(layout) };
+        if ptr.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
+
+        Self { ptr, len, layout }
+    }
+}
+
+impl std::ops::Deref for AlignedBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl std::ops::DerefMut for AlignedBuffer {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl Drop for AlignedBuffer {
+    fn drop(&mut self) {
+        unsafe { std::alloc::dealloc(self.ptr, self.layout) }
+    }
+}
+
+/// A pseudo-random permutation of `0..n`, generated lazily with a keyed Feistel network
+/// so `conv=coverage` can visit every block index exactly once without materializing an
+/// index vector. Round function is a seeded `SipHash-1-3`; out-of-range outputs are
+/// cycle-walked back through the network until they land in `0..n`.
+struct CoverageSequence {
+    n: u64,
+    half_bits: u32,
+    key: u64,
+}
+
+impl CoverageSequence {
+    const ROUNDS: u64 = 4;
+
+    fn new(n: u64, key: u64) -> Self {
+        let bits = Self::domain_bits(n);
+        Self {
+            n,
+            half_bits: bits / 2,
+            key,
+        }
+    }
+
+    /// Smallest even bit width `b` (at least 2) such that `2^b >= n`, so the domain splits
+    /// into two equal, non-empty halves for the Feistel rounds.
+    fn domain_bits(n: u64) -> u32 {
+        if n <= 1 {
+            return 2;
+        }
+        let mut bits = 64 - (n - 1).leading_zeros();
+        if bits % 2 != 0 {
+            bits += 1;
+        }
+        bits.max(2)
+    }
+
+    fn round_function(&self, round: u64, half: u64) -> u64 {
+        let mut hasher = SipHasher13::new_with_keys(self.key, round);
+        hasher.write_u64(half);
+        hasher.finish()
+    }
+
+    fn feistel(&self, value: u64) -> u64 {
+        let mask = (1u64 << self.half_bits) - 1;
+        let mut left = (value >> self.half_bits) & mask;
+        let mut right = value & mask;
+
+        for round in 0..Self::ROUNDS {
+            let next_right = left ^ (self.round_function(round, right) & mask);
+            left = right;
+            right = next_right;
+        }
+
+        (left << self.half_bits) | right
+    }
+
+    /// Maps counter `i` (0..n) to its permuted block index, cycle-walking any Feistel
+    /// output that falls outside `0..n` back through the network.
+    fn permute(&self, mut i: u64) -> u64 {
+        loop {
+            let v = self.feistel(i);
+            if v < self.n {
+                return v;
+            }
+            i = v;
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum StatusLevel {
     None,
@@ -46,6 +163,36 @@ struct Args {
 
     #[arg(long)]
     status: Option<String>,
+
+    #[arg(short = 'j', long, default_value_t = 1)]
+    jobs: u64,
+
+    #[arg(long)]
+    direct: bool,
+
+    #[arg(long)]
+    idle: bool,
+
+    #[arg(long)]
+    seed: Option<u64>,
+
+    #[arg(long)]
+    verify: bool,
+
+    #[arg(long)]
+    coverage: bool,
+
+    #[arg(long)]
+    decompress: bool,
+
+    #[arg(long = "dry-run")]
+    dry_run: bool,
+
+    #[arg(long)]
+    enumerate: bool,
+
+    #[arg(long)]
+    json: bool,
 }
 
 struct RandomDd {
@@ -55,7 +202,19 @@ struct RandomDd {
     noerror: bool,
     sync: bool,
     status_level: StatusLevel,
+    jobs: u64,
+    direct: bool,
+    idle: bool,
+    seed: Option<u64>,
+    verify: bool,
+    coverage: bool,
+    decompress: bool,
+    dry_run: bool,
+    enumerate: bool,
+    json: bool,
     bytes_copied: AtomicU64,
+    blocks_processed: AtomicU64,
+    mismatches: AtomicU64,
     start_time: Instant,
 }
 
@@ -71,6 +230,32 @@ impl RandomDd {
         let noerror = args.conv.iter().any(|c| c == "noerror");
         let sync = args.conv.iter().any(|c| c == "sync");
 
+        if args.jobs == 0 {
+            return Err("--jobs must be at least 1".to_string());
+        }
+
+        if args.verify && args.seed.is_none() {
+            return Err("--verify requires --seed so the write pass can be replayed".to_string());
+        }
+
+        let coverage = args.coverage || args.conv.iter().any(|c| c == "coverage");
+        if coverage && bs_min != bs_max {
+            return Err(
+                "conv=coverage requires a fixed block size (bs must not be a range)".to_string(),
+            );
+        }
+
+        if coverage && args.count.is_some() {
+            return Err(
+                "conv=coverage writes every block exactly once and isn't compatible with --count"
+                    .to_string(),
+            );
+        }
+
+        if args.json && !args.enumerate {
+            return Err("--json only makes sense together with --enumerate".to_string());
+        }
+
         let status_level = match args.status.as_deref() {
             Some("none") => StatusLevel::None,
             Some("progress") => StatusLevel::Progress,
@@ -79,18 +264,109 @@ impl RandomDd {
             Some(s) => return Err(format!("Invalid status value: {s}")),
         };
 
+        let jobs = args.jobs;
+
+        let direct = args.direct;
+        let idle = args.idle;
+        let seed = args.seed;
+        let verify = args.verify;
+        let decompress = args.decompress || args.conv.iter().any(|c| c == "decompress");
+        let dry_run = args.dry_run;
+        let enumerate = args.enumerate;
+        let json = args.json;
+
         Ok(Self {
-            args,
             bs_min,
             bs_max,
             noerror,
             sync,
             status_level,
+            jobs,
+            direct,
+            idle,
+            seed,
+            verify,
+            coverage,
+            decompress,
+            dry_run,
+            enumerate,
+            json,
             bytes_copied: AtomicU64::new(0),
+            blocks_processed: AtomicU64::new(0),
+            mismatches: AtomicU64::new(0),
             start_time: Instant::now(),
+            args,
         })
     }
 
+    /// Builds the RNG that drives `(chunk_size, output_pos)` draws for one worker. With
+    /// `--seed`, each worker gets its own deterministic `ChaCha20Rng` (seeded from the
+    /// base seed plus its worker id) so the whole run's position/size sequence — and, with
+    /// `--verify`, the read-back pass over it — is reproducible. Without `--seed`, falls
+    /// back to the thread-local CSPRNG used everywhere else in this file.
+    fn worker_rng(&self, worker_id: u64) -> Box<dyn RngCore> {
+        match self.seed {
+            Some(seed) => Box::new(ChaCha20Rng::seed_from_u64(seed.wrapping_add(worker_id))),
+            None => Box::new(rand::thread_rng()),
+        }
+    }
+
+    /// Signals that should trigger an immediate stats dump: `SIGUSR1` everywhere,
+    /// plus `SIGINFO` on BSD-family platforms where GNU `dd` users expect Ctrl-T to work.
+    fn stats_signals() -> Vec<i32> {
+        #[allow(unused_mut)]
+        let mut signals = vec![signal_hook::consts::SIGUSR1];
+
+        #[cfg(any(
+            target_os = "macos",
+            target_os = "freebsd",
+            target_os = "openbsd",
+            target_os = "netbsd",
+            target_os = "dragonfly"
+        ))]
+        signals.push(signal_hook::consts::SIGINFO);
+
+        signals
+    }
+
+    /// Installs a background thread that prints an on-demand stats line whenever the
+    /// process receives `SIGUSR1` (or `SIGINFO` on BSD/macOS), independent of `status_level`.
+    fn start_stats_signal_handler(&self) -> Option<JoinHandle<()>> {
+        let mut signals = match Signals::new(Self::stats_signals()) {
+            Ok(signals) => signals,
+            Err(e) => {
+                eprintln!("Failed to install stats signal handler: {e}");
+                return None;
+            }
+        };
+
+        let bytes_copied = &raw const self.bytes_copied as u64;
+        let blocks_processed = &raw const self.blocks_processed as u64;
+        let start_time = self.start_time;
+
+        Some(thread::spawn(move || {
+            let bytes_copied = unsafe { &*(bytes_copied as *const AtomicU64) };
+            let blocks_processed = unsafe { &*(blocks_processed as *const AtomicU64) };
+
+            for _ in signals.forever() {
+                let bytes = bytes_copied.load(Ordering::Relaxed);
+                let blocks = blocks_processed.load(Ordering::Relaxed);
+                let elapsed = start_time.elapsed().as_secs_f64();
+                let speed = if elapsed > 0.0 {
+                    bytes as f64 / elapsed
+                } else {
+                    0.0
+                };
+
+                eprintln!(
+                    "{}, {}, {blocks} blocks",
+                    Self::format_size(bytes),
+                    Self::format_speed(speed)
+                );
+            }
+        }))
+    }
+
     fn parse_size(s: &str) -> Result<u64, String> {
         let s = s.trim().to_lowercase();
         let (num, suffix) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
@@ -228,73 +504,126 @@ impl RandomDd {
         }))
     }
 
-    #[allow(clippy::too_many_lines, clippy::cast_precision_loss)]
-    fn run(&self) -> Result<(), String> {
-        let input_path = self.args.input.as_deref().unwrap_or("/dev/stdin");
-        let output_path = self.args.output.as_deref().unwrap_or("/dev/stdout");
+    /// Queries the logical block (sector) size of the device backing `file` via the
+    /// `BLKSSZGET` ioctl, falling back to 512 bytes for regular files or when the ioctl
+    /// isn't supported. Used to size and align buffers for `O_DIRECT` I/O.
+    fn query_alignment(file: &File) -> u64 {
+        const BLKSSZGET: libc::c_ulong = 0x1268;
 
-        let mut input = File::open(input_path)
-            .map_err(|e| format!("Failed to open input {input_path:?}: {e}"))?;
+        let mut block_size: libc::c_int = 0;
+        let ret = unsafe { libc::ioctl(file.as_raw_fd(), BLKSSZGET, &mut block_size) };
 
-        let mut output = File::options()
-            .write(true)
-            .open(output_path)
-            .map_err(|e| format!("Failed to open output {output_path:?} (file must exist): {e}"))?;
-
-        if let Some(skip) = self.args.skip {
-            let block_size = self.bs_min.max(self.bs_max);
-            input
-                .seek(SeekFrom::Start(skip * block_size))
-                .map_err(|e| format!("Failed to seek input: {e}"))?;
+        if ret == 0 && block_size > 0 {
+            block_size as u64
+        } else {
+            512
         }
+    }
 
-        let output_size = output
-            .metadata()
-            .map_err(|e| format!("Failed to get output file metadata: {e}"))?
-            .len();
+    /// Moves the calling process into the idle I/O scheduling class via `ioprio_set(2)`,
+    /// so a background scrub-style `--direct` run doesn't starve foreground I/O.
+    fn set_idle_io_priority() -> Result<(), String> {
+        const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+        const IOPRIO_CLASS_SHIFT: libc::c_int = 13;
+        const IOPRIO_CLASS_IDLE: libc::c_int = 3;
 
-        if output_size == 0 {
-            return Err(format!(
-                "Output file {output_path:?} has zero size, cannot write to random positions"
-            ));
-        }
+        let ioprio = IOPRIO_CLASS_IDLE << IOPRIO_CLASS_SHIFT;
+        let ret = unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, 0, ioprio) };
 
-        if self.bs_min > output_size {
+        if ret != 0 {
             return Err(format!(
-                "Block size ({}) is larger than output file size ({}), cannot write to random positions",
-                Self::format_size(self.bs_min),
-                Self::format_size(output_size)
+                "Failed to set idle I/O priority: {}",
+                io::Error::last_os_error()
             ));
         }
 
-        let max_blocks = self.args.count.map(std::num::NonZero::get);
-        let speed_limit = self
-            .args
-            .speed
-            .as_ref()
-            .map(|s| Self::parse_size(s))
-            .transpose()
-            .map_err(|e| format!("Failed to parse speed: {e}"))?;
+        Ok(())
+    }
 
-        let bitarray: Arc<Mutex<Vec<u8>>> = if self.status_level == StatusLevel::BitArray {
-            let bitarray_size = output_size.div_ceil(self.bs_min);
-            let byte_count = bitarray_size.div_ceil(8) as usize;
-            eprintln!("Bitarray size: {bitarray_size} bits ({byte_count} bytes)");
-            Arc::new(Mutex::new(vec![0u8; byte_count]))
+    /// Reads up to `buf.len()` bytes starting at `offset`, looping over short reads the
+    /// way `Read::read_exact` would, but via positional I/O so no file-cursor state is
+    /// shared between workers. Returns fewer than `buf.len()` bytes only at EOF.
+    fn read_at_full(input: &File, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+        let mut total = 0;
+        while total < buf.len() {
+            match input.read_at(&mut buf[total..], offset + total as u64) {
+                Ok(0) => break,
+                Ok(n) => total += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(total)
+    }
+
+    /// Prints one planned `(output_pos, chunk_size)` pair for `--enumerate`, as plain
+    /// `pos size` text or, with `--json`, as a newline-delimited JSON object.
+    fn print_planned_block(&self, output_pos: u64, chunk_size: u64) {
+        if self.json {
+            println!(r#"{{"output_pos":{output_pos},"chunk_size":{chunk_size}}}"#);
         } else {
-            Arc::new(Mutex::new(vec![]))
-        };
+            println!("{output_pos} {chunk_size}");
+        }
+    }
 
-        let bitarray_size = output_size.div_ceil(self.bs_min);
+    /// Like [`Self::read_at_full`], but over a plain [`Read`] stream with no notion of a
+    /// byte offset — used for the zstd decoder, whose output isn't seekable.
+    fn read_full(input: &mut dyn Read, buf: &mut [u8]) -> io::Result<usize> {
+        let mut total = 0;
+        while total < buf.len() {
+            match input.read(&mut buf[total..]) {
+                Ok(0) => break,
+                Ok(n) => total += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(total)
+    }
 
-        let progress_thread = self.start_progress_thread(&bitarray, bitarray_size);
-        let mut blocks_processed: u64 = 0;
+    /// Runs the whole scatter pass sequentially over a non-seekable `input` stream (a
+    /// zstd decoder), writing to `output` via positional `write_at` just like
+    /// [`Self::worker_loop`]. Always single-threaded: `--jobs` only shards a seekable
+    /// file, which a decoded stream is not. `--skip` is honored by reading and discarding
+    /// bytes up front rather than seeking.
+    #[allow(clippy::too_many_arguments, clippy::cast_precision_loss)]
+    fn run_decompressed(
+        &self,
+        mut input: Box<dyn Read>,
+        output: &File,
+        skip_offset: u64,
+        max_blocks: Option<u64>,
+        output_size: u64,
+        speed_limit: Option<u64>,
+        align: u64,
+        coverage_sequence: Option<&CoverageSequence>,
+        bitarray: &Arc<Mutex<Vec<u8>>>,
+    ) -> Result<u64, String> {
+        let mut remaining_skip = skip_offset;
+        let mut discard = vec![0u8; 64 * 1024];
+        while remaining_skip > 0 {
+            let want = remaining_skip.min(discard.len() as u64) as usize;
+            let n = input
+                .read(&mut discard[..want])
+                .map_err(|e| format!("Failed to skip input: {e}"))?;
+            if n == 0 {
+                break;
+            }
+            remaining_skip -= n as u64;
+        }
 
-        let mut rng = rand::thread_rng();
+        let mut rng = self.worker_rng(0);
+        let mut blocks_processed: u64 = 0;
+        let mut coverage_counter: u64 = 0;
         let mut last_report = Instant::now();
 
+        let block_budget = match coverage_sequence {
+            Some(sequence) => Some(max_blocks.map_or(sequence.n, |m| m.min(sequence.n))),
+            None => max_blocks,
+        };
+
         loop {
-            if let Some(max) = max_blocks {
+            if let Some(max) = block_budget {
                 if blocks_processed >= max {
                     break;
                 }
@@ -306,29 +635,27 @@ impl RandomDd {
                 rng.gen_range(self.bs_min..=self.bs_max)
             };
 
-            let mut buffer = vec![0u8; chunk_size as usize];
+            let chunk_size = if align > 1 {
+                chunk_size.div_ceil(align) * align
+            } else {
+                chunk_size
+            };
 
-            let read_result = input.read_exact(&mut buffer);
+            let mut buffer = AlignedBuffer::new(chunk_size as usize, align as usize);
+            let read_result = Self::read_full(&mut input, &mut buffer);
 
             let actual_read = match read_result {
-                Ok(()) => chunk_size,
-                Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => {
-                    let bytes_read = input
-                        .read(&mut buffer)
-                        .map_err(|e| format!("Failed to read from input: {e}"))?;
-
-                    if bytes_read == 0 {
-                        break;
-                    }
-
+                Ok(0) => break,
+                Ok(n) if (n as u64) < chunk_size => {
                     if self.sync {
-                        for byte in &mut buffer[bytes_read..] {
+                        for byte in &mut buffer[n..] {
                             *byte = 0;
                         }
                     }
 
-                    bytes_read as u64
+                    n as u64
                 }
+                Ok(n) => n as u64,
                 Err(e) if self.noerror => {
                     eprintln!("Input error (continuing): {e}");
 
@@ -336,49 +663,272 @@ impl RandomDd {
                         buffer.fill(0);
                         chunk_size
                     } else {
-                        let current_pos = input
-                            .stream_position()
-                            .map_err(|e| format!("Failed to get input position: {e}"))?;
-                        input
-                            .seek(SeekFrom::Start(current_pos + chunk_size))
-                            .map_err(|e| format!("Failed to seek past error: {e}"))?;
                         continue;
                     }
                 }
                 Err(e) => return Err(format!("Input error: {e}")),
             };
 
-            let output_pos = rng.gen_range(0..=(output_size.saturating_sub(actual_read)));
+            let output_pos = if let Some(sequence) = coverage_sequence {
+                let block_index = sequence.permute(coverage_counter);
+                coverage_counter += 1;
+                block_index * self.bs_min
+            } else {
+                let output_pos = rng.gen_range(0..=(output_size.saturating_sub(actual_read)));
+                if align > 1 {
+                    (output_pos / align) * align
+                } else {
+                    output_pos
+                }
+            };
 
-            output
-                .seek(SeekFrom::Start(output_pos))
-                .map_err(|e| format!("Failed to seek output to {output_pos}: {e}"))?;
+            // `conv=coverage`'s last block index can leave less than a full block of room
+            // at the end of the output file; clamp so the write/read-back never runs past
+            // `output_size`.
+            let write_len = if coverage_sequence.is_some() {
+                actual_read.min(output_size.saturating_sub(output_pos))
+            } else {
+                actual_read
+            };
+
+            if self.enumerate {
+                self.print_planned_block(output_pos, write_len);
+            }
 
-            if let Err(e) = output.write_all(&buffer[..actual_read as usize]) {
+            let mut wrote_ok = true;
+
+            if self.dry_run {
+                // Planning only — no read-back or write.
+            } else if self.verify {
+                let mut readback = AlignedBuffer::new(write_len as usize, align as usize);
+                match output.read_at(&mut readback, output_pos) {
+                    Ok(n) if n as u64 == write_len && *readback == buffer[..write_len as usize] => {}
+                    Ok(_) => {
+                        eprintln!(
+                            "Verify mismatch at output offset {output_pos} (len {write_len})"
+                        );
+                        self.mismatches.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Err(e) => return Err(format!("Verify read error at {output_pos}: {e}")),
+                }
+            } else if let Err(e) = output.write_at(&buffer[..write_len as usize], output_pos) {
                 if self.noerror {
                     eprintln!("Output error (continuing): {e}");
+                    wrote_ok = false;
                 } else {
                     return Err(format!("Output error: {e}"));
                 }
             }
 
-            if self.status_level == StatusLevel::BitArray {
+            if wrote_ok
+                && (self.status_level == StatusLevel::BitArray || self.coverage && !self.dry_run)
+            {
                 let bit_index = output_pos / self.bs_min;
-                Self::flip_bit(&bitarray, bit_index);
+                Self::flip_bit(bitarray, bit_index);
+            }
+
+            blocks_processed += 1;
+            self.bytes_copied.fetch_add(write_len, Ordering::Relaxed);
+            self.blocks_processed.fetch_add(1, Ordering::Relaxed);
+
+            if self.status_level != StatusLevel::None
+                && self.status_level != StatusLevel::Progress
+                && self.status_level != StatusLevel::BitArray
+                && last_report.elapsed() >= Duration::from_millis(100)
+            {
+                let bytes = self.bytes_copied.load(Ordering::Relaxed);
+                eprint!("\r{}", Self::format_size(bytes));
+                last_report = Instant::now();
+            }
+
+            if let Some(speed) = speed_limit {
+                let elapsed = self.start_time.elapsed().as_secs_f64();
+                let bytes = self.bytes_copied.load(Ordering::Relaxed);
+                let expected = bytes as f64 / speed as f64;
+                if expected > elapsed {
+                    thread::sleep(Duration::from_secs_f64(expected - elapsed));
+                }
+            }
+        }
+
+        Ok(blocks_processed)
+    }
+
+    /// Runs one random-scatter worker against its own disjoint input region
+    /// `[region_start, region_start + region_len)` (or the whole remaining stream when
+    /// `region_len` is `None`), writing to independent random offsets via `write_at` so
+    /// concurrent workers never contend on a shared file cursor. Returns the number of
+    /// blocks this worker processed.
+    #[allow(clippy::too_many_arguments, clippy::cast_precision_loss)]
+    fn worker_loop(
+        &self,
+        worker_id: u64,
+        input: &File,
+        output: &File,
+        region_start: u64,
+        region_len: Option<u64>,
+        block_budget: Option<u64>,
+        output_size: u64,
+        speed_limit: Option<u64>,
+        align: u64,
+        coverage: Option<(&CoverageSequence, u64)>,
+        bitarray: &Arc<Mutex<Vec<u8>>>,
+    ) -> Result<u64, String> {
+        let mut rng = self.worker_rng(worker_id);
+        let mut input_cursor: u64 = 0;
+        let mut blocks_processed: u64 = 0;
+        let mut last_report = Instant::now();
+        let mut coverage_counter = coverage.map_or(0, |(_, start)| start);
+
+        loop {
+            if let Some(max) = block_budget {
+                if blocks_processed >= max {
+                    break;
+                }
+            }
+
+            if let Some(len) = region_len {
+                if input_cursor >= len {
+                    break;
+                }
+            }
+
+            let chunk_size = if self.bs_min == self.bs_max {
+                self.bs_min
+            } else {
+                rng.gen_range(self.bs_min..=self.bs_max)
+            };
+
+            // `--direct` requires every I/O to be a whole number of device sectors.
+            let chunk_size = if align > 1 {
+                chunk_size.div_ceil(align) * align
+            } else {
+                chunk_size
+            };
+
+            let read_len = match region_len {
+                Some(len) => {
+                    // `remaining` (and so the naive clamp) isn't necessarily a multiple
+                    // of `align`, since `region_len` is only guaranteed aligned at its
+                    // start, not at a worker's final, possibly-partial chunk. Round back
+                    // up to a whole sector — the extra bytes either come from the next
+                    // worker's region (harmless for a random scatter) or hit real EOF,
+                    // which the short-read branch below already pads correctly.
+                    let remaining = len - input_cursor;
+                    let wanted = chunk_size.min(remaining);
+                    if align > 1 {
+                        wanted.div_ceil(align) * align
+                    } else {
+                        wanted
+                    }
+                }
+                None => chunk_size,
+            };
+
+            let mut buffer = AlignedBuffer::new(read_len as usize, align as usize);
+            let read_result = Self::read_at_full(input, &mut buffer, region_start + input_cursor);
+
+            let actual_read = match read_result {
+                Ok(0) => break,
+                Ok(n) if (n as u64) < read_len => {
+                    // `--direct` needs every write to cover a whole number of sectors, so
+                    // a short EOF read is zero-padded out to `read_len` (itself a multiple
+                    // of `align`) even without `conv=sync`.
+                    if self.sync || align > 1 {
+                        for byte in &mut buffer[n..] {
+                            *byte = 0;
+                        }
+                    }
+
+                    n as u64
+                }
+                Ok(n) => n as u64,
+                Err(e) if self.noerror => {
+                    eprintln!("Input error (continuing): {e}");
+
+                    if self.sync {
+                        buffer.fill(0);
+                        chunk_size
+                    } else {
+                        input_cursor += chunk_size;
+                        continue;
+                    }
+                }
+                Err(e) => return Err(format!("Input error: {e}")),
+            };
+
+            // The length read from the input this block: padded up to `read_len` for
+            // `--direct` so the I/O stays sector-aligned, or the raw `actual_read`
+            // otherwise. Advancing `input_cursor` by this (rather than `actual_read`)
+            // keeps every later `region_start + input_cursor` offset a multiple of
+            // `align` too.
+            let io_len = if align > 1 { read_len } else { actual_read };
+            input_cursor += io_len;
+
+            let output_pos = if let Some((sequence, _)) = coverage {
+                // Every counter maps to a distinct block index, so the whole file is
+                // covered exactly once regardless of alignment rounding.
+                let block_index = sequence.permute(coverage_counter);
+                coverage_counter += 1;
+                block_index * self.bs_min
+            } else {
+                let output_pos = rng.gen_range(0..=(output_size.saturating_sub(io_len)));
+                if align > 1 {
+                    (output_pos / align) * align
+                } else {
+                    output_pos
+                }
+            };
+
+            // `conv=coverage`'s last block index can leave less than a full block of
+            // room at the end of the output file; clamp so the write/read-back never
+            // runs past `output_size`.
+            let write_len = if coverage.is_some() {
+                io_len.min(output_size.saturating_sub(output_pos))
+            } else {
+                io_len
+            };
+
+            if self.enumerate {
+                self.print_planned_block(output_pos, write_len);
             }
 
-            if let Err(e) = output.flush() {
+            let mut wrote_ok = true;
+
+            if self.dry_run {
+                // Planning only — no read-back or write.
+            } else if self.verify {
+                let mut readback = AlignedBuffer::new(write_len as usize, align as usize);
+                match output.read_at(&mut readback, output_pos) {
+                    Ok(n) if n as u64 == write_len && *readback == buffer[..write_len as usize] => {}
+                    Ok(_) => {
+                        eprintln!("Verify mismatch at output offset {output_pos} (len {write_len})");
+                        self.mismatches.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Err(e) => return Err(format!("Verify read error at {output_pos}: {e}")),
+                }
+            } else if let Err(e) = output.write_at(&buffer[..write_len as usize], output_pos) {
                 if self.noerror {
-                    eprintln!("Flush error: {e}");
+                    eprintln!("Output error (continuing): {e}");
+                    wrote_ok = false;
                 } else {
-                    return Err(format!("Flush error: {e}"));
+                    return Err(format!("Output error: {e}"));
                 }
             }
 
+            if wrote_ok
+                && (self.status_level == StatusLevel::BitArray || self.coverage && !self.dry_run)
+            {
+                let bit_index = output_pos / self.bs_min;
+                Self::flip_bit(bitarray, bit_index);
+            }
+
             blocks_processed += 1;
-            self.bytes_copied.fetch_add(actual_read, Ordering::Relaxed);
+            self.bytes_copied.fetch_add(write_len, Ordering::Relaxed);
+            self.blocks_processed.fetch_add(1, Ordering::Relaxed);
 
-            if self.status_level != StatusLevel::None
+            if worker_id == 0
+                && self.status_level != StatusLevel::None
                 && self.status_level != StatusLevel::Progress
                 && self.status_level != StatusLevel::BitArray
                 && last_report.elapsed() >= Duration::from_millis(100)
@@ -390,17 +940,290 @@ impl RandomDd {
 
             if let Some(speed) = speed_limit {
                 let elapsed = self.start_time.elapsed().as_secs_f64();
-                let expected = (blocks_processed as f64) * chunk_size as f64 / speed as f64;
+                let bytes = self.bytes_copied.load(Ordering::Relaxed);
+                let expected = bytes as f64 / speed as f64;
                 if expected > elapsed {
                     thread::sleep(Duration::from_secs_f64(expected - elapsed));
                 }
             }
         }
 
+        Ok(blocks_processed)
+    }
+
+    #[allow(clippy::too_many_lines, clippy::cast_precision_loss)]
+    fn run(&self) -> Result<(), String> {
+        let input_path = self.args.input.as_deref().unwrap_or("/dev/stdin");
+        let output_path = self.args.output.as_deref().unwrap_or("/dev/stdout");
+
+        let mut input_options = File::options();
+        input_options.read(true);
+        let mut output_options = File::options();
+        if self.dry_run {
+            // `--dry-run` only plans positions/sizes — it never writes, so it doesn't
+            // need write access to the output file.
+            output_options.read(true);
+        } else {
+            output_options.write(true);
+            if self.verify {
+                output_options.read(true);
+            }
+        }
+
+        if self.direct {
+            input_options.custom_flags(libc::O_DIRECT);
+            output_options.custom_flags(libc::O_DIRECT);
+        }
+
+        let input = input_options
+            .open(input_path)
+            .map_err(|e| format!("Failed to open input {input_path:?}: {e}"))?;
+
+        let output = output_options
+            .open(output_path)
+            .map_err(|e| format!("Failed to open output {output_path:?} (file must exist): {e}"))?;
+
+        if self.idle {
+            Self::set_idle_io_priority()?;
+        }
+
+        // `O_DIRECT` requires every read/write offset and length to be a multiple of the
+        // backing device's logical sector size, and input and output can be different
+        // devices with different sector sizes, so align to whichever demands more.
+        let align = if self.direct {
+            Self::query_alignment(&input).max(Self::query_alignment(&output))
+        } else {
+            1
+        };
+
+        // Transparently decompress a zstd input, whether requested via `conv=decompress`
+        // or detected via the zstd frame magic, without disturbing any real file cursor.
+        // Detection is pread-based, so it only works for seekable inputs (regular files,
+        // block devices); a pipe (e.g. piped stdin) returns `ESPIPE` and is left alone —
+        // use `conv=decompress` explicitly for a zstd stream arriving on a pipe. It's also
+        // skipped under `--direct`: a 4-byte unaligned read would fail `O_DIRECT`'s sector
+        // alignment requirement, so `--direct` zstd input needs explicit `conv=decompress`.
+        const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+        let sniffed_zstd = !self.decompress && !self.direct && {
+            let mut magic = [0u8; 4];
+            match input.read_at(&mut magic, 0) {
+                Ok(4) => magic == ZSTD_MAGIC,
+                Ok(_) => false,
+                Err(e) if e.raw_os_error() == Some(libc::ESPIPE) => false,
+                Err(e) => return Err(format!("Failed to sniff input for zstd magic: {e}")),
+            }
+        };
+        let decompress = self.decompress || sniffed_zstd;
+
+        if decompress && self.jobs > 1 {
+            return Err(
+                "--jobs > 1 is incompatible with a zstd input (the decoded stream isn't seekable)"
+                    .to_string(),
+            );
+        }
+
+        let skip_offset = self.args.skip.unwrap_or(0) * self.bs_min.max(self.bs_max);
+        // `O_DIRECT` requires every read offset to land on a device sector boundary, so
+        // round the skip point down to one. Workers derive their region starts from this
+        // value plus a multiple of the region size, which is rounded up to a whole sector
+        // further down, so together the two keep every region start aligned too.
+        let skip_offset = if align > 1 {
+            (skip_offset / align) * align
+        } else {
+            skip_offset
+        };
+
+        let output_size = output
+            .metadata()
+            .map_err(|e| format!("Failed to get output file metadata: {e}"))?
+            .len();
+
+        if output_size == 0 {
+            return Err(format!(
+                "Output file {output_path:?} has zero size, cannot write to random positions"
+            ));
+        }
+
+        if self.bs_min > output_size {
+            return Err(format!(
+                "Block size ({}) is larger than output file size ({}), cannot write to random positions",
+                Self::format_size(self.bs_min),
+                Self::format_size(output_size)
+            ));
+        }
+
+        let max_blocks = self.args.count.map(std::num::NonZero::get);
+        let speed_limit = self
+            .args
+            .speed
+            .as_ref()
+            .map(|s| Self::parse_size(s))
+            .transpose()
+            .map_err(|e| format!("Failed to parse speed: {e}"))?;
+
+        let bitarray_size = output_size.div_ceil(self.bs_min);
+
+        // Coverage mode reuses the same per-block bitarray that `--status=bitarray`
+        // visualizes, even when that status level isn't selected, so the run can assert
+        // every block was actually hit before reporting success.
+        let track_coverage_bits = self.coverage && !self.dry_run;
+        let bitarray: Arc<Mutex<Vec<u8>>> =
+            if self.status_level == StatusLevel::BitArray || track_coverage_bits {
+                let byte_count = bitarray_size.div_ceil(8) as usize;
+                if self.status_level == StatusLevel::BitArray {
+                    eprintln!("Bitarray size: {bitarray_size} bits ({byte_count} bytes)");
+                }
+                Arc::new(Mutex::new(vec![0u8; byte_count]))
+            } else {
+                Arc::new(Mutex::new(vec![]))
+            };
+
+        // `conv=coverage` replaces independent random draws with a lazily-generated
+        // permutation of every block index, shared by all workers, so the file is
+        // overwritten exactly once with no gaps or collisions.
+        let coverage_sequence = if self.coverage {
+            let n = bitarray_size;
+            let seed = self.seed.unwrap_or_else(rand::random);
+            Some(CoverageSequence::new(n, seed))
+        } else {
+            None
+        };
+
+        let progress_thread = self.start_progress_thread(&bitarray, bitarray_size);
+        let stats_signal_thread = self.start_stats_signal_handler();
+
+        let blocks_processed = if decompress {
+            let reader: Box<dyn Read> = Box::new(
+                zstd::Decoder::new(input)
+                    .map_err(|e| format!("Failed to start zstd decoder: {e}"))?,
+            );
+
+            self.run_decompressed(
+                reader,
+                &output,
+                skip_offset,
+                max_blocks,
+                output_size,
+                speed_limit,
+                align,
+                coverage_sequence.as_ref(),
+                &bitarray,
+            )?
+        } else if self.jobs <= 1 {
+            let (budget, coverage) = if let Some(sequence) = &coverage_sequence {
+                let budget = max_blocks.map_or(sequence.n, |m| m.min(sequence.n));
+                (Some(budget), Some((sequence, 0u64)))
+            } else {
+                (max_blocks, None)
+            };
+
+            self.worker_loop(
+                0,
+                &input,
+                &output,
+                skip_offset,
+                None,
+                budget,
+                output_size,
+                speed_limit,
+                align,
+                coverage,
+                &bitarray,
+            )?
+        } else {
+            let input_len = input
+                .metadata()
+                .map_err(|e| format!("Failed to get input file metadata: {e}"))?;
+
+            if !input_len.file_type().is_file() {
+                return Err(
+                    "--jobs > 1 requires a regular file input with a known size to shard reads"
+                        .to_string(),
+                );
+            }
+
+            let remaining = input_len.len().saturating_sub(skip_offset);
+            let region_size = remaining.div_ceil(self.jobs).max(1);
+            // Round each worker's region up to a whole number of sectors so every
+            // `region_start = skip_offset + worker_id * region_size` (already a multiple
+            // of `align` via `skip_offset`) stays sector-aligned for `--direct`.
+            let region_size = if align > 1 {
+                region_size.div_ceil(align) * align
+            } else {
+                region_size
+            };
+            // Split `--count` into disjoint per-worker shares the same way the input
+            // region is split: a worker's share is clamped to what's left of the total
+            // after earlier workers' shares, so the shares sum to exactly `max_blocks`
+            // instead of each rounding up independently and overshooting by up to
+            // `jobs - 1` blocks.
+            let block_region = max_blocks.map(|m| m.div_ceil(self.jobs).max(1));
+
+            thread::scope(|scope| -> Result<u64, String> {
+                let mut handles = Vec::with_capacity(self.jobs as usize);
+
+                for worker_id in 0..self.jobs {
+                    let region_start = skip_offset + worker_id * region_size;
+                    let region_len =
+                        region_size.min(remaining.saturating_sub(worker_id * region_size));
+
+                    let worker_input = input
+                        .try_clone()
+                        .map_err(|e| format!("Failed to clone input handle: {e}"))?;
+                    let worker_output = output
+                        .try_clone()
+                        .map_err(|e| format!("Failed to clone output handle: {e}"))?;
+                    let bitarray = &bitarray;
+
+                    let (worker_budget, worker_coverage) =
+                        if let Some(sequence) = &coverage_sequence {
+                            let counter_region = sequence.n.div_ceil(self.jobs).max(1);
+                            let counter_start = worker_id * counter_region;
+                            let counter_count = counter_region
+                                .min(sequence.n.saturating_sub(counter_start));
+                            (Some(counter_count), Some((sequence, counter_start)))
+                        } else {
+                            let worker_budget = max_blocks.zip(block_region).map(|(m, region)| {
+                                region.min(m.saturating_sub(worker_id * region))
+                            });
+                            (worker_budget, None)
+                        };
+
+                    handles.push(scope.spawn(move || {
+                        self.worker_loop(
+                            worker_id,
+                            &worker_input,
+                            &worker_output,
+                            region_start,
+                            Some(region_len),
+                            worker_budget,
+                            output_size,
+                            speed_limit,
+                            align,
+                            worker_coverage,
+                            bitarray,
+                        )
+                    }));
+                }
+
+                let mut total = 0;
+                for handle in handles {
+                    total += handle
+                        .join()
+                        .map_err(|_| "Worker thread panicked".to_string())??;
+                }
+                Ok(total)
+            })?
+        };
+
         if let Some(handle) = progress_thread {
             std::mem::forget(handle);
         }
 
+        if let Some(handle) = stats_signal_thread {
+            std::mem::forget(handle);
+        }
+
         if self.status_level != StatusLevel::None {
             let bytes = self.bytes_copied.load(Ordering::Relaxed);
             let elapsed = self.start_time.elapsed().as_secs_f64();
@@ -438,6 +1261,34 @@ impl RandomDd {
             );
         }
 
+        if self.verify {
+            let mismatches = self.mismatches.load(Ordering::Relaxed);
+            if mismatches > 0 {
+                return Err(format!("Verify failed: {mismatches} mismatched offset(s)"));
+            }
+            eprintln!("Verify OK: {blocks_processed} blocks matched the seeded sequence");
+        }
+
+        if self.coverage && !self.dry_run {
+            // Every permuted counter maps to a distinct block index, so `flip_bit` (which
+            // toggles) flips each block's bit at most once, on its successful write, and a
+            // plain popcount over the bitarray is exactly the number of distinct blocks
+            // written (or, under `--verify`, read back).
+            let covered: u64 = bitarray
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|byte| u64::from(byte.count_ones()))
+                .sum();
+            let action = if self.verify { "verified" } else { "written" };
+            if covered != bitarray_size {
+                return Err(format!(
+                    "conv=coverage incomplete: {covered}/{bitarray_size} blocks were {action}"
+                ));
+            }
+            eprintln!("Coverage OK: all {bitarray_size} blocks were {action} exactly once");
+        }
+
         Ok(())
     }
 }